@@ -18,9 +18,16 @@
 extern crate glfw;
 #[macro_use] extern crate log;
 
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
 use std::sync::mpsc::Receiver;
 
-use glfw::{Glfw, WindowMode, WindowHint, Window, WindowEvent, OpenGlProfileHint};
+use glfw::{Glfw, WindowMode, WindowHint, Window, WindowEvent, OpenGlProfileHint, ClientApiHint, Monitor};
+use glfw::Error as GlfwError;
+
+/// The event receiver returned alongside a `Window` by `glfw::Glfw::create_window()`.
+pub type WindowEvents = Receiver<(f64, WindowEvent)>;
 
 /// Builder for a GLFW window with robust OpenGL context selection.
 ///
@@ -54,6 +61,102 @@ pub struct WindowBuilder<'glfw, 'title, 'monitor> {
     mode: Option<WindowMode<'monitor>>,
     common_hints: Vec<WindowHint>,
     try_hints: Vec<Vec<WindowHint>>,
+    framebuffer_try_hints: Vec<Vec<WindowHint>>,
+    no_api: bool,
+    use_primary_monitor: bool,
+    best_video_mode: bool,
+}
+
+/// The actual attributes of a created OpenGL context, read back via
+/// `glfwGetWindowAttrib`.
+///
+/// The framebuffer and context hints passed to `create()` are only matched
+/// "as closely as possible" by GLFW, so the context it actually creates can
+/// differ from what was requested. `create_with_info()` returns this
+/// alongside the window so callers can branch on what they actually got
+/// instead of assuming their top-priority hint succeeded.
+#[derive(Copy, Clone, Debug)]
+pub struct ContextInfo {
+    /// The client API the context was created for.
+    pub client_api: ClientApiHint,
+    /// The major version of the client API.
+    pub version_major: u32,
+    /// The minor version of the client API.
+    pub version_minor: u32,
+    /// The OpenGL profile of the context.
+    pub profile: OpenGlProfileHint,
+    /// Whether the context is forward-compatible, i.e. it has no support for
+    /// deprecated functionality.
+    pub forward_compat: bool,
+}
+
+impl ContextInfo {
+    fn read_from(window: &Window) -> ContextInfo {
+        let version = window.get_context_version();
+
+        ContextInfo {
+            client_api: match window.get_client_api() {
+                api if api == glfw::ffi::OPENGL_ES_API => ClientApiHint::OpenGlEs,
+                api if api == glfw::ffi::NO_API => ClientApiHint::NoApi,
+                _ => ClientApiHint::OpenGl,
+            },
+            version_major: version.major as u32,
+            version_minor: version.minor as u32,
+            profile: match window.get_opengl_profile() {
+                profile if profile == glfw::ffi::OPENGL_CORE_PROFILE => OpenGlProfileHint::Core,
+                profile if profile == glfw::ffi::OPENGL_COMPAT_PROFILE => OpenGlProfileHint::Compat,
+                _ => OpenGlProfileHint::Any,
+            },
+            forward_compat: window.is_opengl_forward_compat(),
+        }
+    }
+}
+
+/// A single rejected `glfwCreateWindow` attempt, captured by `try_create()`.
+#[derive(Clone, Debug)]
+pub struct FailedAttempt {
+    /// The window hints in effect for this attempt: the `common_hints()`
+    /// followed by the hints of the `try_hints()` group and framebuffer tier.
+    pub hints: Vec<WindowHint>,
+    /// The GLFW errors reported while this attempt was being made.
+    pub errors: Vec<(GlfwError, String)>,
+}
+
+/// Returned by `try_create()` when every hint combination was rejected.
+///
+/// Contains one `FailedAttempt` per combination of `try_hints()` and
+/// framebuffer fallback tier that was tried, in the order they were
+/// attempted, together with whatever GLFW reported about why each one
+/// failed.
+#[derive(Clone, Debug)]
+pub struct WindowCreationError {
+    pub attempts: Vec<FailedAttempt>,
+}
+
+impl fmt::Display for WindowCreationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to create a GLFW window after {} attempt(s)", self.attempts.len())?;
+
+        for attempt in self.attempts.iter() {
+            if attempt.errors.is_empty() {
+                write!(f, "\n  {:?}: rejected with no reported error", attempt.hints)?;
+            } else {
+                for (error, description) in attempt.errors.iter() {
+                    write!(f, "\n  {:?}: {}: {}", attempt.hints, error, description)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for WindowCreationError {}
+
+/// `ErrorCallback` function used by `try_create()` to record GLFW's
+/// diagnostics for each rejected attempt.
+fn record_glfw_error(error: GlfwError, description: String, errors: &Rc<RefCell<Vec<(GlfwError, String)>>>) {
+    errors.borrow_mut().push((error, description));
 }
 
 impl<'glfw, 'title, 'monitor> WindowBuilder<'glfw, 'title, 'monitor> {
@@ -66,7 +169,11 @@ impl<'glfw, 'title, 'monitor> WindowBuilder<'glfw, 'title, 'monitor> {
             title: None,
             mode: None,
             try_hints: vec![],
+            framebuffer_try_hints: vec![],
             common_hints: vec![],
+            no_api: false,
+            use_primary_monitor: false,
+            best_video_mode: false,
         }
     }
 }
@@ -93,6 +200,41 @@ impl<'glfw, 'title, 'monitor, 'hints> WindowBuilder<'glfw, 'title, 'monitor> {
         self
     }
 
+    /// Sets the window to be created in exclusive fullscreen mode on `monitor`.
+    ///
+    /// Shorthand for `mode(WindowMode::FullScreen(monitor))`. Combine with
+    /// `best_video_mode()` to also pick a matching video mode for a
+    /// flicker-free exclusive fullscreen window.
+    pub fn fullscreen_on(self, monitor: &'monitor Monitor) -> WindowBuilder<'glfw, 'title, 'monitor> {
+        self.mode(WindowMode::FullScreen(monitor))
+    }
+
+    /// Sets the window to be created in exclusive fullscreen mode on the
+    /// primary monitor, i.e. the monitor where the OS task bar or menu bar
+    /// usually lives.
+    ///
+    /// Unlike `fullscreen_on()`, this doesn't require the caller to already
+    /// have a `&Monitor` in scope: the primary monitor is looked up from
+    /// `create()`/`try_create()` directly. Combine with `best_video_mode()`
+    /// for a flicker-free exclusive fullscreen window.
+    pub fn fullscreen_primary(mut self) -> WindowBuilder<'glfw, 'title, 'monitor> {
+        self.use_primary_monitor = true;
+        self
+    }
+
+    /// Before creating the window, picks the video mode of the target
+    /// monitor whose resolution most closely matches the requested `size()`
+    /// (or the monitor's current video mode if no size was given), and
+    /// applies its colour bit depths and refresh rate as `common_hints()`.
+    ///
+    /// This is the standard recipe for a flicker-free exclusive fullscreen
+    /// window. It only has an effect when combined with `fullscreen_on()` or
+    /// `fullscreen_primary()`.
+    pub fn best_video_mode(mut self) -> WindowBuilder<'glfw, 'title, 'monitor> {
+        self.best_video_mode = true;
+        self
+    }
+
     /// Tell the OpenGL context that it can expect no errors from your program
     pub fn no_error(self) -> WindowBuilder<'glfw, 'title, 'monitor> {
         self.common_hints(&[
@@ -100,6 +242,54 @@ impl<'glfw, 'title, 'monitor, 'hints> WindowBuilder<'glfw, 'title, 'monitor> {
         ])
     }
 
+    /// Explicitly selects which client API to create the context for, e.g.
+    /// to force OpenGL ES (`ClientApiHint::OpenGlEs`) or desktop OpenGL
+    /// (`ClientApiHint::OpenGl`).
+    ///
+    /// `try_gles_context_hints()` already sets this hint as part of its
+    /// fallback hints, overriding this one; `try_modern_context_hints()`
+    /// doesn't set it. This is mostly useful when building a custom set of
+    /// `try_hints()`.
+    pub fn client_api(self, api: ClientApiHint) -> WindowBuilder<'glfw, 'title, 'monitor> {
+        self.common_hints(&[
+            WindowHint::ClientApi(api)
+        ])
+    }
+
+    /// Creates the window with no client API context at all, as required by
+    /// Vulkan applications that use GLFW only for windowing and input.
+    ///
+    /// This sets `ClientApiHint::NoApi` and makes `create()` skip the
+    /// context-version fallback loop entirely, since there's no context
+    /// version to fall back on: a window is created in a single attempt
+    /// using just the `common_hints()` given.
+    ///
+    /// This does not require the `glfw` crate's `"vulkan"` feature; unlike
+    /// `vulkan_supported()`, no-API window creation doesn't go through
+    /// `glfw`'s Vulkan-specific bindings.
+    pub fn no_api(mut self) -> WindowBuilder<'glfw, 'title, 'monitor> {
+        self.no_api = true;
+        self.common_hints(&[
+            WindowHint::ClientApi(ClientApiHint::NoApi)
+        ])
+    }
+
+    /// Checks whether the GLFW library found a Vulkan loader and at least one
+    /// minimally functional ICD.
+    ///
+    /// Passthrough for `Glfw::vulkan_supported()`, useful for checking
+    /// availability before building a `no_api()` window for Vulkan.
+    ///
+    /// `Glfw::vulkan_supported()` is only compiled into the `glfw` crate when
+    /// its own `"vulkan"` feature is enabled, which is not on by default.
+    /// Callers of this method need to enable that feature on their `glfw`
+    /// dependency (`glfw = { version = "...", features = ["vulkan"] }`), or
+    /// have it enabled transitively by another dependency; otherwise this
+    /// method fails to compile with "no method named `vulkan_supported`".
+    pub fn vulkan_supported(&self) -> bool {
+        self.glfw.vulkan_supported()
+    }
+
     /// Set the desired refresh rate of the GLFW window. If set to `None`,
     /// it will try for the highest refresh rate possible
     pub fn refresh_rate(self, rate: Option<u32>) -> WindowBuilder<'glfw, 'title, 'monitor> {
@@ -214,6 +404,144 @@ impl<'glfw, 'title, 'monitor, 'hints> WindowBuilder<'glfw, 'title, 'monitor> {
             ])
     }
 
+    /// Applies a number of `try_hints()` with the goal of getting an OpenGL
+    /// ES context, for targeting embedded Linux and ARM SBCs where OpenGL ES
+    /// is the common (or only) client API.
+    ///
+    /// Specifically, this adds four `try_hints()` calls that try for ES 3.2,
+    /// 3.1, 3.0 and 2.0, in that order, mirroring
+    /// `try_modern_context_hints()`.
+    pub fn try_gles_context_hints(self) -> WindowBuilder<'glfw, 'title, 'monitor> {
+        self.try_hints(&[
+            WindowHint::ClientApi(ClientApiHint::OpenGlEs),
+            WindowHint::ContextVersion(3, 2),
+        ])
+            .try_hints(&[
+                WindowHint::ClientApi(ClientApiHint::OpenGlEs),
+                WindowHint::ContextVersion(3, 1),
+            ])
+            .try_hints(&[
+                WindowHint::ClientApi(ClientApiHint::OpenGlEs),
+                WindowHint::ContextVersion(3, 0),
+            ])
+            .try_hints(&[
+                WindowHint::ClientApi(ClientApiHint::OpenGlEs),
+                WindowHint::ContextVersion(2, 0),
+            ])
+    }
+
+    /// Sets the desired bit depths of the red, green, blue and alpha
+    /// components of the default framebuffer.
+    ///
+    /// These are soft constraints that GLFW matches "as closely as possible",
+    /// so a window may still be created with a different framebuffer format
+    /// if the exact depths aren't available.
+    pub fn color_bits(self, red: u32, green: u32, blue: u32, alpha: u32) -> WindowBuilder<'glfw, 'title, 'monitor> {
+        self.common_hints(&[
+            WindowHint::RedBits(Some(red)),
+            WindowHint::GreenBits(Some(green)),
+            WindowHint::BlueBits(Some(blue)),
+            WindowHint::AlphaBits(Some(alpha)),
+        ])
+    }
+
+    /// Sets the desired bit depth of the default framebuffer's depth buffer.
+    ///
+    /// Combine with `try_framebuffer_degradation()` to fall back to a window
+    /// without a depth buffer if one can't be had.
+    pub fn depth_bits(self, bits: u32) -> WindowBuilder<'glfw, 'title, 'monitor> {
+        self.common_hints(&[
+            WindowHint::DepthBits(Some(bits))
+        ])
+    }
+
+    /// Sets the desired bit depth of the default framebuffer's stencil buffer.
+    ///
+    /// Combine with `try_framebuffer_degradation()` to fall back to a window
+    /// without a stencil buffer if one can't be had.
+    pub fn stencil_bits(self, bits: u32) -> WindowBuilder<'glfw, 'title, 'monitor> {
+        self.common_hints(&[
+            WindowHint::StencilBits(Some(bits))
+        ])
+    }
+
+    /// Sets the desired number of samples to use for multisampling.
+    ///
+    /// Combine with `try_framebuffer_degradation()` to fall back to lower
+    /// sample counts (and eventually no multisampling at all) if the
+    /// requested number of samples isn't available.
+    pub fn samples(self, samples: u32) -> WindowBuilder<'glfw, 'title, 'monitor> {
+        self.common_hints(&[
+            WindowHint::Samples(Some(samples))
+        ])
+    }
+
+    /// Expands the framebuffer-quality hints requested via `samples()`,
+    /// `depth_bits()` and `stencil_bits()` into a descending ladder of
+    /// fallback framebuffer configurations, analogous to what
+    /// `try_modern_context_hints()` does for the context version.
+    ///
+    /// `GLFW_SAMPLES`, `GLFW_DEPTH_BITS` and `GLFW_STENCIL_BITS` are soft
+    /// constraints matched "as closely as possible" by GLFW, which means
+    /// asking for 8x MSAA or a 32-bit depth buffer on weak hardware can
+    /// silently give you something else, or fail outright when combined with
+    /// a strict context. This method instead builds an explicit priority
+    /// order: it first tries the requested `samples()` count, then halves it
+    /// repeatedly down to `0`, and finally drops `depth_bits()` and
+    /// `stencil_bits()` altogether as a last resort.
+    ///
+    /// These framebuffer fallbacks are combined multiplicatively with the
+    /// context fallbacks added via `try_hints()` (or
+    /// `try_modern_context_hints()`): `create()` tries every context version
+    /// at every framebuffer quality tier, context version first, before
+    /// moving on to the next context version.
+    pub fn try_framebuffer_degradation(mut self) -> WindowBuilder<'glfw, 'title, 'monitor> {
+        let mut samples = self.common_hints.iter().filter_map(|hint| match *hint {
+            WindowHint::Samples(samples) => Some(samples.unwrap_or(0)),
+            _ => None,
+        }).next();
+
+        let has_depth_bits = self.common_hints.iter().any(|hint| matches!(*hint, WindowHint::DepthBits(_)));
+
+        let has_stencil_bits = self.common_hints.iter().any(|hint| matches!(*hint, WindowHint::StencilBits(_)));
+
+        loop {
+            self.framebuffer_try_hints.push(match samples {
+                Some(samples) => vec![WindowHint::Samples(Some(samples))],
+                None => vec![],
+            });
+
+            match samples {
+                Some(0) | None => break,
+                Some(remaining) => samples = Some(remaining / 2),
+            }
+        }
+
+        if has_depth_bits || has_stencil_bits {
+            // Carry the fully-degraded sample count forward too, so this
+            // last-resort tier is the actual lowest-quality combination
+            // (no MSAA, no preference for depth or stencil bits) rather than
+            // reverting to the originally requested `samples()` count.
+            let mut degraded = if samples.is_some() {
+                vec![WindowHint::Samples(Some(0))]
+            } else {
+                vec![]
+            };
+
+            if has_depth_bits {
+                degraded.push(WindowHint::DepthBits(None));
+            }
+
+            if has_stencil_bits {
+                degraded.push(WindowHint::StencilBits(None));
+            }
+
+            self.framebuffer_try_hints.push(degraded);
+        }
+
+        self
+    }
+
     /// Try to create the window.
     ///
     /// This method tries each of the possible window hints given
@@ -222,43 +550,223 @@ impl<'glfw, 'title, 'monitor, 'hints> WindowBuilder<'glfw, 'title, 'monitor> {
     /// In order for that to work, it has to disable the `Glfw` error callback,
     /// so you'll have to rebind it afterwards.
     ///
-    /// For every set of window hints given with a `try_hints()`, this method
+    /// If `try_framebuffer_degradation()` was used, each `try_hints()` group
+    /// is additionally attempted at every framebuffer quality tier it
+    /// produced, from highest to lowest quality, before moving on to the next
+    /// `try_hints()` group.
+    ///
+    /// If `no_api()` was used, none of the above applies: a single attempt is
+    /// made with just the `common_hints()` given, since there's no context
+    /// version or framebuffer fallback to try.
+    ///
+    /// On total failure, this discards GLFW's diagnostics for every rejected
+    /// attempt; use `try_create()` to get them back.
+    pub fn create(self) -> Option<(Window, WindowEvents)> {
+        self.try_create().ok()
+    }
+
+    /// Like `create()`, but on total failure returns a `WindowCreationError`
+    /// describing every attempt that was made and why GLFW rejected it,
+    /// instead of discarding that information and returning `None`.
     ///
-    /// - Applies the window hints of all `common_hints()` given.
-    /// - Applies the window hints of the current `try_hints()`.
-    /// - Tries to call `glfw.create_window()` with the given arguments
-    ///   (or default values).
-    /// - Returns on successful window creation.
-    pub fn create(self) -> Option<(Window, Receiver<(f64, WindowEvent)>)> {
-        let WindowBuilder { glfw, common_hints, try_hints, size, aspect_ratio, title, mode } = self;
-
-        let (width, height) = size.unwrap_or((640, 480));
-        let title = title.unwrap_or("GLFW Window");
+    /// To capture it, this installs a temporary error callback for the
+    /// duration of the attempts, which replaces whatever `Glfw` error
+    /// callback was previously set. `Glfw` has no way to read back a
+    /// previously installed callback, so the old one isn't restored: on
+    /// return, the error callback is simply disabled, exactly as `create()`
+    /// already documents doing.
+    pub fn try_create(self) -> Result<(Window, WindowEvents), WindowCreationError> {
+        let WindowBuilder {
+            glfw, common_hints, try_hints, framebuffer_try_hints, no_api,
+            use_primary_monitor, best_video_mode, size, aspect_ratio, title, mode,
+        } = self;
+
         let mode = mode.unwrap_or(WindowMode::Windowed);
 
-        for setup in try_hints.iter() {
-            glfw.default_window_hints();
+        let hints = HintPlan {
+            common_hints, try_hints, framebuffer_try_hints, no_api,
+            want_best_video_mode: best_video_mode,
+        };
 
-            for hint in common_hints.iter() {
-                glfw.window_hint(*hint);
-            }
+        if use_primary_monitor {
+            // `with_primary_monitor_mut()` takes an `FnMut`, so the closure
+            // can't move these owned `Vec`s out of its captures; clone them
+            // in instead.
+            glfw.with_primary_monitor_mut(|glfw, monitor| {
+                let mode = monitor.map_or(WindowMode::Windowed, WindowMode::FullScreen);
+
+                create_with_hints(glfw, mode, hints.clone(), size, aspect_ratio, title)
+            })
+        } else {
+            create_with_hints(glfw, mode, hints, size, aspect_ratio, title)
+        }
+    }
 
-            for hint in setup.iter() {
+    /// Like `create()`, but also returns the actual attributes of the
+    /// created context as a `ContextInfo`, read back via
+    /// `glfwGetWindowAttrib`.
+    ///
+    /// This lets callers see what GLFW actually gave them (context version,
+    /// profile, forward-compatibility, client API) rather than assuming
+    /// their top-priority hint succeeded.
+    pub fn create_with_info(self) -> Option<(Window, WindowEvents, ContextInfo)> {
+        self.create().map(|(window, events)| {
+            let info = ContextInfo::read_from(&window);
+
+            (window, events, info)
+        })
+    }
+}
+
+/// Picks the video mode of `monitor` whose resolution most closely matches
+/// `requested_size` (or its current video mode if `None`), applies the
+/// colour bit depths and refresh rate of that video mode to `common_hints`,
+/// and returns the size to create the window with.
+fn best_video_mode_size(monitor: &Monitor, requested_size: Option<(u32, u32)>, common_hints: &mut Vec<WindowHint>) -> (u32, u32) {
+    let current_mode = monitor.get_video_mode();
+
+    let target_mode = match requested_size {
+        Some((width, height)) => monitor.get_video_modes().into_iter()
+            .min_by_key(|vid_mode| {
+                (vid_mode.width as i64 - width as i64).abs() + (vid_mode.height as i64 - height as i64).abs()
+            })
+            .or(current_mode),
+        None => current_mode,
+    };
+
+    match target_mode {
+        Some(vid_mode) => {
+            common_hints.push(WindowHint::RedBits(Some(vid_mode.red_bits)));
+            common_hints.push(WindowHint::GreenBits(Some(vid_mode.green_bits)));
+            common_hints.push(WindowHint::BlueBits(Some(vid_mode.blue_bits)));
+            common_hints.push(WindowHint::RefreshRate(Some(vid_mode.refresh_rate)));
+
+            (vid_mode.width, vid_mode.height)
+        },
+        None => requested_size.unwrap_or((640, 480)),
+    }
+}
+
+/// The hint-related fields of a `WindowBuilder`, bundled together so that
+/// `create_with_hints()` doesn't need a parameter per field.
+#[derive(Clone)]
+struct HintPlan {
+    common_hints: Vec<WindowHint>,
+    try_hints: Vec<Vec<WindowHint>>,
+    framebuffer_try_hints: Vec<Vec<WindowHint>>,
+    no_api: bool,
+    want_best_video_mode: bool,
+}
+
+/// Shared implementation behind `create()` and `try_create()` (and, via
+/// `create()`, `create_with_info()`), once the window mode has been fully
+/// resolved, i.e. `fullscreen_primary()`'s primary-monitor lookup has
+/// already happened.
+fn create_with_hints(
+    glfw: &mut Glfw,
+    mode: WindowMode,
+    hints: HintPlan,
+    size: Option<(u32, u32)>,
+    aspect_ratio: Option<(u32, u32)>,
+    title: Option<&str>,
+) -> Result<(Window, WindowEvents), WindowCreationError> {
+    let HintPlan {
+        mut common_hints, try_hints, framebuffer_try_hints, no_api, want_best_video_mode,
+    } = hints;
+
+    let title = title.unwrap_or("GLFW Window");
+
+    let (width, height) = match mode {
+        WindowMode::FullScreen(monitor) if want_best_video_mode =>
+            best_video_mode_size(monitor, size, &mut common_hints),
+        _ => size.unwrap_or((640, 480)),
+    };
+
+    let errors = Rc::new(RefCell::new(Vec::new()));
+
+    glfw.set_error_callback(Some(glfw::Callback {
+        f: record_glfw_error,
+        data: errors.clone(),
+    }));
+
+    let mut attempts = Vec::new();
+
+    // Applies `common_hints` plus every hint in `extra_hint_groups`, and
+    // attempts to create the window. On failure, records the hints used
+    // together with whatever GLFW reported about them into `attempts`.
+    let mut try_hint_groups = |glfw: &mut Glfw, extra_hint_groups: &[&[WindowHint]]| -> Option<(Window, WindowEvents)> {
+        glfw.default_window_hints();
+
+        for hint in common_hints.iter() {
+            glfw.window_hint(*hint);
+        }
+
+        for group in extra_hint_groups.iter() {
+            for hint in group.iter() {
                 glfw.window_hint(*hint);
             }
+        }
+
+        errors.borrow_mut().clear();
 
-            if let Some((mut window, events)) = glfw.create_window(width, height, title, mode) {
-                info!("Created GLFW window with GL context hints {:?} and {:?}", common_hints, setup);
+        match glfw.create_window(width, height, title, mode) {
+            Some((mut window, events)) => {
+                info!("Created GLFW window with hints {:?} and {:?}", common_hints, extra_hint_groups);
 
                 if let Some((numer, denom)) = aspect_ratio {
                     window.set_aspect_ratio(numer, denom);
                 }
 
-                return Some((window, events));
-            } else {
-                debug!("Couldn't create a context for hints {:?} and {:?}", common_hints, setup);
+                Some((window, events))
+            },
+            None => {
+                debug!("Couldn't create a context for hints {:?} and {:?}", common_hints, extra_hint_groups);
+
+                let mut hints = common_hints.clone();
+                for group in extra_hint_groups.iter() {
+                    hints.extend(group.iter().cloned());
+                }
+
+                attempts.push(FailedAttempt {
+                    hints,
+                    errors: errors.borrow_mut().drain(..).collect(),
+                });
+
+                None
+            },
+        }
+    };
+
+    // A `no_api()` window has no context version to fall back on, so
+    // just make a single attempt with the common hints.
+    let result = if no_api {
+        try_hint_groups(glfw, &[])
+    } else {
+        // If no framebuffer degradation was requested, fall back to a
+        // single empty tier so the loop below still runs exactly once
+        // per `setup`.
+        let no_framebuffer_hints = vec![];
+        let framebuffer_setups = if framebuffer_try_hints.is_empty() {
+            std::slice::from_ref(&no_framebuffer_hints)
+        } else {
+            &framebuffer_try_hints[..]
+        };
+
+        let mut result = None;
+
+        'search: for setup in try_hints.iter() {
+            for framebuffer_setup in framebuffer_setups.iter() {
+                if let Some(created) = try_hint_groups(glfw, &[&setup[..], &framebuffer_setup[..]]) {
+                    result = Some(created);
+                    break 'search;
+                }
             }
         }
-        None
-    }
+
+        result
+    };
+
+    glfw.set_error_callback::<Rc<RefCell<Vec<(GlfwError, String)>>>>(None);
+
+    result.ok_or(WindowCreationError { attempts })
 }